@@ -4,12 +4,17 @@ use fst::Set;
 use once_cell::sync::Lazy;
 
 use crate::detection::is_cjk;
-use crate::normalizer::{DeunicodeNormalizer, IdentityNormalizer, LowercaseNormalizer, Normalizer};
+use crate::normalizer::{
+    CompatibilityDecompositionNormalizer, ControlCharNormalizer, DeunicodeNormalizer,
+    IdentityNormalizer, LowercaseNormalizer, NonspacingMarkNormalizer, Normalizer,
+    PinyinNormalizer, RomajiNormalizer,
+};
 use crate::processors::{
     ChineseTranslationPreProcessor, IdentityPreProcessor, PreProcessor, ProcessedText,
 };
 use crate::token_classifier::TokenClassifier;
-use crate::tokenizer::{Jieba, LegacyMeilisearch, TokenStream, Tokenizer, UnicodeSegmenter};
+use crate::token_filter::TokenFilter;
+use crate::tokenizer::{Japanese, Jieba, LegacyMeilisearch, TokenStream, Tokenizer, UnicodeSegmenter};
 use crate::Token;
 
 static DEFAULT_PIPELINE: Lazy<Pipeline> = Lazy::new(Pipeline::default);
@@ -18,6 +23,7 @@ pub struct Pipeline {
     pre_processor: Box<dyn PreProcessor + 'static>,
     tokenizer: Box<dyn Tokenizer + 'static>,
     normalizer: Box<dyn Normalizer + 'static>,
+    token_filters: Vec<Box<dyn TokenFilter + 'static>>,
 }
 
 impl Default for Pipeline {
@@ -26,6 +32,7 @@ impl Default for Pipeline {
             pre_processor: Box::new(IdentityPreProcessor),
             tokenizer: Box::new(UnicodeSegmenter),
             normalizer: Box::new(IdentityNormalizer),
+            token_filters: Vec::new(),
         }
     }
 }
@@ -45,12 +52,53 @@ impl Pipeline {
         self.normalizer = Box::new(normalizer);
         self
     }
+
+    /// Sets the chain of filters applied, in order, to each token after normalization.
+    /// A filter returning `None` removes the token from the stream.
+    pub fn set_token_filters(mut self, token_filters: Vec<Box<dyn TokenFilter>>) -> Self {
+        self.token_filters = token_filters;
+        self
+    }
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
-pub enum Language {
-    English,
-    Other,
+macro_rules! make_language {
+    ($($language:tt), +) => {
+        #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+        pub enum Language {
+            $($language),+,
+            Other,
+        }
+
+        impl From<whatlang::Lang> for Language {
+            fn from(other: whatlang::Lang) -> Language {
+                match other {
+                    $(whatlang::Lang::$language => Language::$language), +
+                }
+            }
+        }
+
+        impl Language {
+            /// Maps back to the `whatlang::Lang` this variant was built from, used to
+            /// build a restricted candidate set for detection. `Language::Other` has no
+            /// `whatlang` counterpart.
+            fn to_whatlang(&self) -> Option<whatlang::Lang> {
+                match self {
+                    $(Language::$language => Some(whatlang::Lang::$language)), +,
+                    Language::Other => None,
+                }
+            }
+        }
+    };
+}
+
+make_language! {
+    Epo, Eng, Rus, Cmn, Spa, Por, Ita, Ben, Fra, Deu, Ukr, Kat,
+    Arb, Hin, Jpn, Heb, Yid, Pol, Amh, Jav, Kor, Nob, Dan, Swe,
+    Fin, Tur, Nld, Hun, Ces, Ell, Bul, Bel, Mar, Kan, Ron, Slv,
+    Hrv, Srp, Mkd, Lit, Lav, Est, Tam, Vie, Urd, Tha, Guj, Uzb,
+    Pan, Aze, Ind, Tel, Pes, Mal, Ori, Mya, Nep, Sin, Khm, Tuk,
+    Kaz, Afr, Zul, Sna, Aka, Lat, Slk, Cat, Tgl, Hye, Kir, Tgk,
+    Mon
 }
 
 macro_rules! make_script {
@@ -104,6 +152,51 @@ pub struct AnalyzerConfig<A> {
     /// document tokenization if the document contains several languages
     pub pipeline_map: HashMap<(Script, Language), Pipeline>,
     pub stop_words: Set<A>,
+    /// restricts language detection to this set of languages, which both speeds up
+    /// detection and prevents misclassifying short strings; `None` detects among all
+    /// languages `whatlang` supports
+    pub allow_list: Option<Vec<Language>>,
+}
+
+/// Script-agnostic normalizer chain giving diacritic-insensitive matching: strip control
+/// characters, decompose to NFKD, drop the resulting combining marks, then lowercase.
+fn diacritic_insensitive_normalizer() -> Vec<Box<dyn Normalizer>> {
+    vec![
+        Box::new(ControlCharNormalizer),
+        Box::new(CompatibilityDecompositionNormalizer),
+        Box::new(NonspacingMarkNormalizer),
+        Box::new(LowercaseNormalizer),
+    ]
+}
+
+/// Opt-in Mandarin pipeline that romanizes tokens to Pinyin, e.g. "北京" becomes
+/// "beijing", so a user typing on a Latin keyboard can still match CJK tokens. Not
+/// installed by `default_with_stopwords` (see its Mandarin pipeline) since it discards
+/// the original Chinese characters; insert it over `(Script::Mandarin, Language::Other)`
+/// in a custom `pipeline_map` to opt in:
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use fst::Set;
+/// use meilisearch_tokenizer::{pinyin_pipeline, Analyzer, AnalyzerConfig, Language, Script};
+///
+/// let mut pipeline_map = HashMap::new();
+/// pipeline_map.insert((Script::Mandarin, Language::Other), pinyin_pipeline());
+/// let stop_words = Set::default();
+/// let analyzer = Analyzer::new(AnalyzerConfig::new(pipeline_map, &stop_words));
+/// let analyzed = analyzer.analyze("北京");
+/// assert_eq!("beijing", analyzed.tokens().next().unwrap().text());
+/// ```
+pub fn pinyin_pipeline() -> Pipeline {
+    let normalizer: Vec<Box<dyn Normalizer>> = vec![
+        Box::new(ControlCharNormalizer),
+        Box::new(PinyinNormalizer),
+        Box::new(LowercaseNormalizer),
+    ];
+    Pipeline::default()
+        .set_pre_processor(ChineseTranslationPreProcessor)
+        .set_tokenizer(Jieba::default())
+        .set_normalizer(normalizer)
 }
 
 impl<A> AnalyzerConfig<A>
@@ -115,6 +208,7 @@ where
 
         // Latin script specialized pipeline
         let latin_normalizer: Vec<Box<dyn Normalizer>> = vec![
+            Box::new(ControlCharNormalizer),
             Box::new(DeunicodeNormalizer::default()),
             Box::new(LowercaseNormalizer),
         ];
@@ -125,11 +219,16 @@ where
                 .set_normalizer(latin_normalizer),
         );
 
-        // Chinese script specialized pipeline
-        let chinese_deunicoder =
-            DeunicodeNormalizer::new(&|text: &str| text.chars().next().map_or(false, is_cjk));
-        let chinese_normalizer: Vec<Box<dyn Normalizer>> =
-            vec![Box::new(chinese_deunicoder), Box::new(LowercaseNormalizer)];
+        // Chinese script specialized pipeline. Callers who want Latin-keyboard-searchable
+        // Mandarin text instead of the original Chinese characters can opt into
+        // `pinyin_pipeline()` over this `(Script::Mandarin, Language::Other)` entry.
+        let chinese_normalizer: Vec<Box<dyn Normalizer>> = vec![
+            Box::new(ControlCharNormalizer),
+            Box::new(DeunicodeNormalizer::new(&|text: &str| {
+                text.chars().next().map_or(false, |c| !is_cjk(c))
+            })),
+            Box::new(LowercaseNormalizer),
+        ];
         pipeline_map.insert(
             (Script::Mandarin, Language::Other),
             Pipeline::default()
@@ -138,9 +237,47 @@ where
                 .set_normalizer(chinese_normalizer),
         );
 
+        // Japanese pipeline: dictionary segmentation followed by kana-to-romaji
+        // transliteration. Kanji-heavy Japanese text is tagged `Script::Mandarin` by
+        // `whatlang`, so it's distinguished from Chinese by `Language::Jpn` rather than
+        // by script alone. The three entries below share a single `Japanese` tokenizer
+        // (and its lazily-loaded lindera dictionary) via `Arc` rather than each paying
+        // for their own dictionary load.
+        let japanese = std::sync::Arc::new(Japanese::default());
+        let japanese_pipeline = |japanese: std::sync::Arc<Japanese>| {
+            let normalizer: Vec<Box<dyn Normalizer>> =
+                vec![Box::new(ControlCharNormalizer), Box::new(RomajiNormalizer)];
+            Pipeline::default()
+                .set_tokenizer(japanese)
+                .set_normalizer(normalizer)
+        };
+        pipeline_map.insert(
+            (Script::Hiragana, Language::Other),
+            japanese_pipeline(japanese.clone()),
+        );
+        pipeline_map.insert(
+            (Script::Katakana, Language::Other),
+            japanese_pipeline(japanese.clone()),
+        );
+        pipeline_map.insert(
+            (Script::Mandarin, Language::Jpn),
+            japanese_pipeline(japanese),
+        );
+
+        // Script-agnostic diacritic-insensitive pipeline: NFKD-decompose, then drop the
+        // resulting combining marks, so e.g. Cyrillic "й"/"и" or Arabic with/without
+        // harakat normalize consistently.
+        for script in [Script::Cyrillic, Script::Greek, Script::Arabic, Script::Hebrew] {
+            pipeline_map.insert(
+                (script, Language::Other),
+                Pipeline::default().set_normalizer(diacritic_insensitive_normalizer()),
+            );
+        }
+
         AnalyzerConfig {
             pipeline_map,
             stop_words,
+            allow_list: None,
         }
     }
 
@@ -148,8 +285,16 @@ where
         Self {
             pipeline_map,
             stop_words,
+            allow_list: None,
         }
     }
+
+    /// Restricts language detection to `allow_list`, e.g. for a corpus known to only
+    /// contain English and French.
+    pub fn with_allow_list(mut self, allow_list: Vec<Language>) -> Self {
+        self.allow_list = Some(allow_list);
+        self
+    }
 }
 
 pub struct Analyzer<A> {
@@ -176,6 +321,7 @@ where
             .tokenizer
             .tokenize(&self.processed)
             .map(move |t| self.pipeline.normalizer.normalize(t))
+            .filter_map(move |t| self.pipeline.token_filters.filter(t))
             .map(move |t| self.classifier.classify(t));
         TokenStream {
             inner: Box::new(stream),
@@ -184,8 +330,11 @@ where
 
     /// Attaches each token to its corresponding portion of the original text.
     pub fn reconstruct(&'a self) -> impl Iterator<Item = (&'a str, Token<'a>)> {
-        self.tokens()
-            .map(move |t| (&self.processed.original[t.byte_start..t.byte_end], t))
+        self.tokens().map(move |t| {
+            let start = self.processed.original_offset(t.byte_start);
+            let end = self.processed.original_offset(t.byte_end);
+            (&self.processed.original[start..end], t)
+        })
     }
 }
 
@@ -214,7 +363,18 @@ impl<A> Analyzer<A> {
     /// assert!("the" == tokens.next().unwrap().text());
     /// ```
     pub fn analyze<'t>(&'t self, text: &'t str) -> AnalyzedText<'t, A> {
-        let pipeline = self.pipeline_from(text);
+        self.analyze_with_allow_list(text, self.config.allow_list.as_deref())
+    }
+
+    /// Same as [`analyze`](Analyzer::analyze), but overrides the `AnalyzerConfig`'s
+    /// `allow_list` for this call, restricting language detection to `allow_list`
+    /// when it is `Some`.
+    pub fn analyze_with_allow_list<'t>(
+        &'t self,
+        text: &'t str,
+        allow_list: Option<&[Language]>,
+    ) -> AnalyzedText<'t, A> {
+        let pipeline = self.pipeline_from(text, allow_list);
         let processed = pipeline.pre_processor.process(text);
         let classifier = TokenClassifier::new(&self.config.stop_words);
 
@@ -231,9 +391,9 @@ impl<A> Analyzer<A> {
     /// if no Script is detected or no pipeline corresponds to the Script,
     /// the function try to get the default pipeline in the map;
     /// if no default pipeline exist in the map return the librairy DEFAULT_PIPELINE.
-    fn pipeline_from(&self, text: &str) -> &Pipeline {
+    fn pipeline_from(&self, text: &str, allow_list: Option<&[Language]>) -> &Pipeline {
         let script = self.detect_script(text);
-        let language = self.detect_lang(text);
+        let language = self.detect_lang(text, allow_list);
         self.config
             .pipeline_map
             .get(&(script, language))
@@ -254,9 +414,25 @@ impl<A> Analyzer<A> {
             .unwrap_or(Script::Other)
     }
 
-    /// detect lang (dummy)
-    fn detect_lang(&self, _text: &str) -> Language {
-        Language::Other
+    /// detect language with whatlang, optionally restricted to `allow_list`;
+    /// falls back to `Language::Other` when nothing is detected or confidence is low
+    fn detect_lang(&self, text: &str, allow_list: Option<&[Language]>) -> Language {
+        let detector = match allow_list {
+            Some(languages) => {
+                let whatlang_languages: Vec<whatlang::Lang> = languages
+                    .iter()
+                    .filter_map(|language| language.to_whatlang())
+                    .collect();
+                whatlang::Detector::with_allowlist(whatlang_languages)
+            }
+            None => whatlang::Detector::new(),
+        };
+
+        detector
+            .detect(text)
+            .filter(|info| info.is_reliable())
+            .map(|info| Language::from(info.lang()))
+            .unwrap_or(Language::Other)
     }
 }
 
@@ -264,6 +440,8 @@ impl<A> Analyzer<A> {
 mod test {
     use super::*;
     use crate::normalizer::LowercaseNormalizer;
+    use crate::token_filter::{AlphaNumOnlyFilter, RemoveLongFilter};
+    use crate::tokenizer::NgramTokenizer;
 
     #[test]
     fn test_simple_latin() {
@@ -378,6 +556,118 @@ mod test {
         assert_eq!("the", analyzed.tokens().next().unwrap().text());
     }
 
+    #[test]
+    fn test_token_filter_chain() {
+        let filters: Vec<Box<dyn TokenFilter>> = vec![
+            Box::new(RemoveLongFilter::new(5)),
+            Box::new(AlphaNumOnlyFilter),
+        ];
+        let mut pipeline_map: HashMap<(Script, Language), Pipeline> = HashMap::new();
+        pipeline_map.insert(
+            (Script::Latin, Language::Other),
+            Pipeline::default().set_token_filters(filters),
+        );
+
+        let stop_words = Set::default();
+        let analyzer = Analyzer::new(AnalyzerConfig::new(pipeline_map, &stop_words));
+        let analyzed = analyzer.analyze("a elephantine cat, dog!");
+        let words: Vec<_> = analyzed.tokens().map(|t| t.text().to_owned()).collect();
+
+        // "elephantine" is dropped by `RemoveLongFilter` (longer than 5 bytes), and the
+        // separators are dropped by `AlphaNumOnlyFilter` (no alphanumeric character),
+        // short-circuiting through the whole chain.
+        assert_eq!(words, vec!["a", "cat", "dog"]);
+    }
+
+    #[test]
+    fn test_allow_list_restricts_detected_language() {
+        let mut pipeline_map: HashMap<(Script, Language), Pipeline> = HashMap::new();
+        pipeline_map.insert(
+            (Script::Latin, Language::Eng),
+            Pipeline::default().set_normalizer(LowercaseNormalizer),
+        );
+        pipeline_map.insert((Script::Latin, Language::Other), Pipeline::default());
+
+        let stop_words = Set::default();
+        let analyzer = Analyzer::new(AnalyzerConfig::new(pipeline_map, &stop_words));
+        let orig = "The quick brown fox jumps over the lazy dog near the riverbank at dawn.";
+
+        // Detected as English by default, so the `Language::Eng` pipeline (which
+        // lowercases) is picked.
+        let analyzed = analyzer.analyze(orig);
+        assert_eq!("the", analyzed.tokens().next().unwrap().text());
+
+        // Restricting candidates to French forces `Language::Other`, since the
+        // (actually English) text can't be reliably classified as French; the
+        // case-preserving `Language::Other` pipeline is picked instead.
+        let analyzed = analyzer.analyze_with_allow_list(orig, Some(&[Language::Fra]));
+        assert_eq!("The", analyzed.tokens().next().unwrap().text());
+    }
+
+    #[test]
+    fn test_diacritic_insensitive_pipeline() {
+        let stop_words = Set::default();
+        let analyzer = Analyzer::new(AnalyzerConfig::default_with_stopwords(&stop_words));
+
+        // NFKD-decomposes "Ά" into "Α" plus a combining tonos, which
+        // `NonspacingMarkNormalizer` then drops, leaving lowercase unaccented Greek.
+        let analyzed = analyzer.analyze("Άλφα");
+        assert_eq!("αλφα", analyzed.tokens().next().unwrap().text());
+    }
+
+    #[test]
+    fn test_japanese_pipeline_romanizes_kana() {
+        let stop_words = Set::default();
+        let analyzer = Analyzer::new(AnalyzerConfig::default_with_stopwords(&stop_words));
+
+        // Hiragana is dictionary-segmented by `Japanese`, then romanized by
+        // `RomajiNormalizer`.
+        let analyzed = analyzer.analyze("こんにちは");
+        let words: Vec<_> = analyzed.tokens().map(|t| t.text().to_owned()).collect();
+        assert_eq!(words, vec!["konnichiha"]);
+    }
+
+    #[test]
+    fn test_ngram_tokenizer() {
+        let mut pipeline_map: HashMap<(Script, Language), Pipeline> = HashMap::new();
+        pipeline_map.insert(
+            (Script::Latin, Language::Other),
+            Pipeline::default().set_tokenizer(NgramTokenizer::new(2, 3, false)),
+        );
+
+        let stop_words = Set::default();
+        let analyzer = Analyzer::new(AnalyzerConfig::new(pipeline_map, &stop_words));
+        let analyzed = analyzer.analyze("abcd");
+        let words: Vec<_> = analyzed.tokens().map(|t| t.text().to_owned()).collect();
+        assert_eq!(words, vec!["ab", "bc", "cd", "abc", "bcd"]);
+    }
+
+    #[test]
+    fn test_ngram_tokenizer_edges_only() {
+        let mut pipeline_map: HashMap<(Script, Language), Pipeline> = HashMap::new();
+        pipeline_map.insert(
+            (Script::Latin, Language::Other),
+            Pipeline::default().set_tokenizer(NgramTokenizer::new(2, 3, true)),
+        );
+
+        let stop_words = Set::default();
+        let analyzer = Analyzer::new(AnalyzerConfig::new(pipeline_map, &stop_words));
+        let analyzed = analyzer.analyze("abcd");
+        let words: Vec<_> = analyzed.tokens().map(|t| t.text().to_owned()).collect();
+        assert_eq!(words, vec!["ab", "abc"]);
+    }
+
+    #[test]
+    fn test_pinyin_pipeline() {
+        let mut pipeline_map: HashMap<(Script, Language), Pipeline> = HashMap::new();
+        pipeline_map.insert((Script::Mandarin, Language::Other), pinyin_pipeline());
+
+        let stop_words = Set::default();
+        let analyzer = Analyzer::new(AnalyzerConfig::new(pipeline_map, &stop_words));
+        let analyzed = analyzer.analyze("北京");
+        assert_eq!("beijing", analyzed.tokens().next().unwrap().text());
+    }
+
     #[test]
     fn test_reconstruct_latin() {
         let stop_words = Set::default();
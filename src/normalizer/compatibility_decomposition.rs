@@ -0,0 +1,18 @@
+use std::borrow::Cow;
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::normalizer::Normalizer;
+use crate::Token;
+
+/// Applies Unicode compatibility decomposition (NFKD), so ligatures and fullwidth forms
+/// decompose into their base characters, e.g. ahead of `NonspacingMarkNormalizer`
+/// stripping the resulting combining marks.
+pub struct CompatibilityDecompositionNormalizer;
+
+impl Normalizer for CompatibilityDecompositionNormalizer {
+    fn normalize<'a>(&self, mut token: Token<'a>) -> Token<'a> {
+        token.word = Cow::Owned(token.word.nfkd().collect());
+        token
+    }
+}
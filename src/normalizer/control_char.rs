@@ -0,0 +1,23 @@
+use std::borrow::Cow;
+
+use unicode_categories::UnicodeCategories;
+
+use crate::normalizer::Normalizer;
+use crate::Token;
+
+/// Strips Unicode control (Cc) and format (Cf) characters, e.g. stray BOMs, zero-width
+/// joiners, or bidi control characters left over in the source text.
+pub struct ControlCharNormalizer;
+
+impl Normalizer for ControlCharNormalizer {
+    fn normalize<'a>(&self, mut token: Token<'a>) -> Token<'a> {
+        token.word = Cow::Owned(
+            token
+                .word
+                .chars()
+                .filter(|c| !c.is_control() && !c.is_other_format())
+                .collect(),
+        );
+        token
+    }
+}
@@ -0,0 +1,33 @@
+use std::borrow::Cow;
+
+use deunicode::deunicode;
+
+use crate::normalizer::Normalizer;
+use crate::Token;
+
+/// Ascii-folds a token's text, optionally restricted to tokens whose text satisfies a
+/// predicate (e.g. only tokens that don't start with a CJK character).
+pub struct DeunicodeNormalizer<'a> {
+    should_deunicode: &'a dyn Fn(&str) -> bool,
+}
+
+impl<'a> DeunicodeNormalizer<'a> {
+    pub fn new(should_deunicode: &'a dyn Fn(&str) -> bool) -> Self {
+        Self { should_deunicode }
+    }
+}
+
+impl<'a> Default for DeunicodeNormalizer<'a> {
+    fn default() -> Self {
+        Self::new(&|_| true)
+    }
+}
+
+impl<'a> Normalizer for DeunicodeNormalizer<'a> {
+    fn normalize<'b>(&self, mut token: Token<'b>) -> Token<'b> {
+        if (self.should_deunicode)(token.word.as_ref()) {
+            token.word = Cow::Owned(deunicode(token.word.as_ref()));
+        }
+        token
+    }
+}
@@ -0,0 +1,13 @@
+use std::borrow::Cow;
+
+use crate::normalizer::Normalizer;
+use crate::Token;
+
+pub struct LowercaseNormalizer;
+
+impl Normalizer for LowercaseNormalizer {
+    fn normalize<'a>(&self, mut token: Token<'a>) -> Token<'a> {
+        token.word = Cow::Owned(token.word.to_lowercase());
+        token
+    }
+}
@@ -0,0 +1,41 @@
+mod compatibility_decomposition;
+mod control_char;
+mod deunicode;
+mod identity;
+mod lowercase;
+mod nonspacing_mark;
+mod pinyin;
+mod romaji;
+
+pub use compatibility_decomposition::CompatibilityDecompositionNormalizer;
+pub use control_char::ControlCharNormalizer;
+pub use deunicode::DeunicodeNormalizer;
+pub use identity::IdentityNormalizer;
+pub use lowercase::LowercaseNormalizer;
+pub use nonspacing_mark::NonspacingMarkNormalizer;
+pub use pinyin::PinyinNormalizer;
+pub use romaji::RomajiNormalizer;
+
+use crate::Token;
+
+pub trait Normalizer: Sync + Send {
+    fn normalize<'a>(&self, token: Token<'a>) -> Token<'a>;
+}
+
+impl<T> Normalizer for Box<T>
+where
+    T: Normalizer + ?Sized,
+{
+    fn normalize<'a>(&self, token: Token<'a>) -> Token<'a> {
+        self.as_ref().normalize(token)
+    }
+}
+
+/// Allows a chain of normalizers to be used wherever a single `Normalizer` is expected,
+/// applying each one in turn.
+impl Normalizer for Vec<Box<dyn Normalizer>> {
+    fn normalize<'a>(&self, token: Token<'a>) -> Token<'a> {
+        self.iter()
+            .fold(token, |token, normalizer| normalizer.normalize(token))
+    }
+}
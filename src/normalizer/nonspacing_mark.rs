@@ -0,0 +1,25 @@
+use std::borrow::Cow;
+
+use unicode_categories::UnicodeCategories;
+
+use crate::normalizer::Normalizer;
+use crate::Token;
+
+/// Drops Unicode combining marks (general category Mn), giving diacritic-insensitive
+/// matching across scripts (Cyrillic, Greek, Arabic harakat, Latin accents, ...). Meant
+/// to run after a decomposition normalizer (NFD/NFKD) has split base characters from
+/// their marks.
+pub struct NonspacingMarkNormalizer;
+
+impl Normalizer for NonspacingMarkNormalizer {
+    fn normalize<'a>(&self, mut token: Token<'a>) -> Token<'a> {
+        token.word = Cow::Owned(
+            token
+                .word
+                .chars()
+                .filter(|c| !c.is_mark_nonspacing())
+                .collect(),
+        );
+        token
+    }
+}
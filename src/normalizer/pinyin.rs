@@ -0,0 +1,59 @@
+use std::borrow::Cow;
+
+use pinyin::ToPinyin;
+
+use crate::normalizer::Normalizer;
+use crate::Token;
+
+/// Romanizes Mandarin tokens to Pinyin (tone marks stripped), e.g. "北京" becomes
+/// "beijing", so text typed on a Latin keyboard can still match CJK tokens.
+///
+/// Non-CJK characters are passed through unchanged. Polyphonic characters take
+/// their first/most common reading to keep the output deterministic.
+pub struct PinyinNormalizer;
+
+impl Normalizer for PinyinNormalizer {
+    fn normalize<'a>(&self, mut token: Token<'a>) -> Token<'a> {
+        let mut result = String::with_capacity(token.word.len());
+        for c in token.word.chars() {
+            match c.to_pinyin() {
+                Some(pinyin) => result.push_str(pinyin.plain()),
+                None => result.push(c),
+            }
+        }
+        token.word = Cow::Owned(result);
+        token
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::TokenKind;
+
+    fn token(word: &str) -> Token {
+        Token {
+            kind: TokenKind::Word,
+            word: Cow::Borrowed(word),
+            byte_start: 0,
+            byte_end: word.len(),
+        }
+    }
+
+    #[test]
+    fn test_normalize_cjk_token() {
+        assert_eq!("beijing", PinyinNormalizer.normalize(token("北京")).word);
+    }
+
+    #[test]
+    fn test_normalize_non_cjk_token_is_unchanged() {
+        assert_eq!("hello", PinyinNormalizer.normalize(token("hello")).word);
+    }
+
+    #[test]
+    fn test_normalize_polyphonic_char_takes_first_reading() {
+        // "重" is polyphonic (zhòng "heavy" / chóng "again"); `ToPinyin` always
+        // yields its first, most common reading, so this stays deterministic.
+        assert_eq!("zhong", PinyinNormalizer.normalize(token("重")).word);
+    }
+}
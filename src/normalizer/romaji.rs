@@ -0,0 +1,17 @@
+use std::borrow::Cow;
+
+use wana_kana::to_romaji::to_romaji;
+
+use crate::normalizer::Normalizer;
+use crate::Token;
+
+/// Transliterates kana (hiragana/katakana) to romaji, e.g. "とうきょう" becomes
+/// "toukyou", so Japanese tokens can be matched by typing on a Latin keyboard.
+pub struct RomajiNormalizer;
+
+impl Normalizer for RomajiNormalizer {
+    fn normalize<'a>(&self, mut token: Token<'a>) -> Token<'a> {
+        token.word = Cow::Owned(to_romaji(token.word.as_ref()));
+        token
+    }
+}
@@ -0,0 +1,105 @@
+use std::borrow::Cow;
+
+use crate::processors::{PreProcessor, ProcessedText};
+
+/// Longest phrase (in characters) looked up in the Traditional→Simplified dictionary
+/// before falling back to a single-character match.
+const MAX_PHRASE_CHARS: usize = 8;
+
+/// Converts Traditional Chinese to Simplified Chinese using a longest-match scan over
+/// `fast2s`'s phrase dictionary, falling back to `fast2s`'s dedicated single-character
+/// table (not the phrase dictionary, which only covers multi-character entries and
+/// would miss standalone Traditional characters that never appear as part of a phrase).
+pub struct ChineseTranslationPreProcessor;
+
+impl PreProcessor for ChineseTranslationPreProcessor {
+    fn process<'a>(&self, s: &'a str) -> ProcessedText<'a> {
+        let chars: Vec<(usize, char)> = s.char_indices().collect();
+        if chars.is_empty() {
+            return ProcessedText::identity(s, Cow::Borrowed(s));
+        }
+
+        let mut processed = String::with_capacity(s.len());
+        let mut offset_map = Vec::with_capacity(chars.len() + 1);
+        let mut i = 0;
+
+        while i < chars.len() {
+            let max_len = (chars.len() - i).min(MAX_PHRASE_CHARS);
+            let mut matched = None;
+            for len in (1..=max_len).rev() {
+                let end_byte = chars.get(i + len).map_or(s.len(), |&(byte, _)| byte);
+                let candidate = &s[chars[i].0..end_byte];
+                if let Some(simplified) = fast2s::PHRASES.get(candidate) {
+                    matched = Some((len, *simplified));
+                    break;
+                }
+            }
+            let (len, simplified) = matched.unwrap_or_else(|| {
+                let candidate = &s[chars[i].0..chars[i].0 + chars[i].1.len_utf8()];
+                (1, fast2s::CHARACTERS.get(candidate).copied().unwrap_or(candidate))
+            });
+
+            // Record a breakpoint for every source character the matched unit
+            // consumes, even though the whole unit is pushed to `processed` in one
+            // go, so `original_offset` can still resolve a byte offset that lands
+            // in the middle of a unit whose conversion isn't char-count-preserving.
+            for (j, byte_offset_in_unit) in unit_breakpoints(simplified, len).into_iter().enumerate() {
+                offset_map.push((processed.len() + byte_offset_in_unit, chars[i + j].0));
+            }
+            processed.push_str(simplified);
+            i += len;
+        }
+        offset_map.push((processed.len(), s.len()));
+
+        ProcessedText {
+            processed: Cow::Owned(processed),
+            original: s,
+            offset_map,
+        }
+    }
+}
+
+/// Distributes `unit_len` source characters proportionally over `simplified`'s own
+/// chars, returning, for each source character, the byte offset into `simplified`
+/// where that character's share of the conversion begins. Used so a unit whose
+/// conversion isn't char-count-preserving (`unit_len != simplified.chars().count()`)
+/// still gets one `offset_map` breakpoint per source character rather than one for
+/// the whole unit.
+fn unit_breakpoints(simplified: &str, unit_len: usize) -> Vec<usize> {
+    let simplified_char_starts: Vec<usize> =
+        simplified.char_indices().map(|(byte, _)| byte).collect();
+    (0..unit_len)
+        .map(|j| {
+            let simplified_char_idx = simplified_char_starts.len() * j / unit_len;
+            simplified_char_starts
+                .get(simplified_char_idx)
+                .copied()
+                .unwrap_or(simplified.len())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_unit_breakpoints_char_count_preserving() {
+        // 2 source chars -> 2-char (6-byte) output: one breakpoint per output char.
+        assert_eq!(unit_breakpoints("简体", 2), vec![0, 3]);
+    }
+
+    #[test]
+    fn test_unit_breakpoints_shrinking_unit() {
+        // 3 source chars collapse into a single-char (3-byte) output: every source
+        // character's share starts at the same (only) output char.
+        assert_eq!(unit_breakpoints("国", 3), vec![0, 0, 0]);
+    }
+
+    #[test]
+    fn test_unit_breakpoints_growing_unit() {
+        // 2 source chars expand into a 3-char output: the breakpoints are
+        // proportionally spread rather than both pinned to byte 0.
+        assert_eq!(unit_breakpoints("什么啊", 2), vec![0, 3]);
+    }
+}
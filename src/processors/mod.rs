@@ -12,6 +12,42 @@ pub use eraser::Eraser;
 pub struct ProcessedText<'a> {
     pub(crate) processed: Cow<'a, str>,
     pub(crate) original: &'a str,
+    /// Maps a byte offset in `processed` back to the corresponding byte offset in
+    /// `original`, as breakpoints sorted by processed offset (a length-preserving
+    /// preprocessor records only `(0, 0)`; `ChineseTranslationPreProcessor` records one
+    /// per source character, so several may share a processed offset when a converted
+    /// phrase shrinks), plus a final sentinel mapping `processed.len()` to
+    /// `original.len()`. This lets `reconstruct` resolve the
+    /// original span of a token even when a preprocessor is not length-preserving
+    /// (e.g. Traditional to Simplified Chinese conversion).
+    pub(crate) offset_map: Vec<(usize, usize)>,
+}
+
+impl<'a> ProcessedText<'a> {
+    /// Builds a `ProcessedText` for a preprocessor that doesn't change byte offsets,
+    /// i.e. whose offset map is the identity.
+    pub(crate) fn identity(original: &'a str, processed: Cow<'a, str>) -> Self {
+        let len = original.len();
+        ProcessedText {
+            processed,
+            original,
+            offset_map: vec![(0, 0), (len, len)],
+        }
+    }
+
+    /// Maps a byte offset in `self.processed` back to the corresponding byte offset in
+    /// `self.original`.
+    pub(crate) fn original_offset(&self, processed_byte_offset: usize) -> usize {
+        let idx = match self
+            .offset_map
+            .binary_search_by_key(&processed_byte_offset, |&(processed, _)| processed)
+        {
+            Ok(idx) => idx,
+            Err(idx) => idx - 1,
+        };
+        let (processed_start, original_start) = self.offset_map[idx];
+        original_start + (processed_byte_offset - processed_start)
+    }
 }
 
 pub trait PreProcessor: Sync + Send {
@@ -26,3 +62,44 @@ where
         self.as_ref().process(s)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// `original` is 2 (1-byte) chars, `processed` is a single matched unit that
+    /// expanded them into a 3-char (9-byte) replacement, with one breakpoint
+    /// recorded per source character (mirroring
+    /// `ChineseTranslationPreProcessor`'s per-character offset map) rather than one
+    /// for the whole unit.
+    fn growing_unit() -> ProcessedText<'static> {
+        ProcessedText {
+            processed: Cow::Borrowed("什么啊"),
+            original: "xy",
+            offset_map: vec![(0, 0), (3, 1), (9, 2)],
+        }
+    }
+
+    #[test]
+    fn test_original_offset_at_recorded_breakpoints() {
+        let text = growing_unit();
+        assert_eq!(text.original_offset(0), 0);
+        assert_eq!(text.original_offset(3), 1);
+    }
+
+    #[test]
+    fn test_original_offset_mid_unit_interpolates_within_its_breakpoint() {
+        let text = growing_unit();
+        // Byte 1 and 2 of "什" aren't recorded breakpoints (CJK chars are 3 bytes);
+        // they fall back to the last breakpoint at or before them and interpolate
+        // from there, same as every other non-length-preserving preprocessor.
+        assert_eq!(text.original_offset(1), 1);
+        assert_eq!(text.original_offset(2), 2);
+        assert_eq!(text.original_offset(4), 2);
+    }
+
+    #[test]
+    fn test_original_offset_at_end_sentinel() {
+        assert_eq!(growing_unit().original_offset(9), 2);
+    }
+}
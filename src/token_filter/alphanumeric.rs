@@ -0,0 +1,16 @@
+use crate::token_filter::TokenFilter;
+use crate::Token;
+
+/// Drops tokens that contain no alphanumeric character, e.g. stray punctuation left
+/// over from tokenization.
+pub struct AlphaNumOnlyFilter;
+
+impl TokenFilter for AlphaNumOnlyFilter {
+    fn filter<'a>(&self, token: Token<'a>) -> Option<Token<'a>> {
+        if token.word.chars().any(|c| c.is_alphanumeric()) {
+            Some(token)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,35 @@
+mod alphanumeric;
+mod remove_long;
+mod split_compound_words;
+mod stemmer;
+
+pub use alphanumeric::AlphaNumOnlyFilter;
+pub use remove_long::RemoveLongFilter;
+pub use split_compound_words::SplitCompoundWordsFilter;
+pub use stemmer::StemmerFilter;
+
+use crate::Token;
+
+/// Runs after normalization and can rewrite or drop a token, mirroring tantivy's
+/// analyzer filters. Returning `None` removes the token from the stream entirely.
+pub trait TokenFilter: Sync + Send {
+    fn filter<'a>(&self, token: Token<'a>) -> Option<Token<'a>>;
+}
+
+impl<T> TokenFilter for Box<T>
+where
+    T: TokenFilter + ?Sized,
+{
+    fn filter<'a>(&self, token: Token<'a>) -> Option<Token<'a>> {
+        self.as_ref().filter(token)
+    }
+}
+
+/// Allows a chain of filters to be used wherever a single `TokenFilter` is expected,
+/// applying each one in turn and short-circuiting as soon as one drops the token.
+impl TokenFilter for Vec<Box<dyn TokenFilter>> {
+    fn filter<'a>(&self, token: Token<'a>) -> Option<Token<'a>> {
+        self.iter()
+            .try_fold(token, |token, filter| filter.filter(token))
+    }
+}
@@ -0,0 +1,24 @@
+use crate::token_filter::TokenFilter;
+use crate::Token;
+
+/// Drops tokens whose text is longer than `max_bytes`, so oversized tokens (e.g. long
+/// URLs or base64 blobs) don't bloat the index.
+pub struct RemoveLongFilter {
+    max_bytes: usize,
+}
+
+impl RemoveLongFilter {
+    pub fn new(max_bytes: usize) -> Self {
+        Self { max_bytes }
+    }
+}
+
+impl TokenFilter for RemoveLongFilter {
+    fn filter<'a>(&self, token: Token<'a>) -> Option<Token<'a>> {
+        if token.word.len() <= self.max_bytes {
+            Some(token)
+        } else {
+            None
+        }
+    }
+}
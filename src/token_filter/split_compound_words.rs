@@ -0,0 +1,101 @@
+use std::borrow::Cow;
+
+use fst::Set;
+
+use crate::token_filter::TokenFilter;
+use crate::Token;
+
+/// Recursively splits a compound word into its longest dictionary sub-words, useful
+/// for languages like German where compounds (e.g. "Bundesgesundheitsministerium")
+/// aren't tokenized as separate words upstream.
+///
+/// A `TokenFilter` can only keep or drop a single token, so a successful split is
+/// re-joined with a space rather than expanded into several tokens; the original
+/// byte span is left untouched so `reconstruct` keeps working. Words the dictionary
+/// can't fully decompose are passed through unchanged.
+pub struct SplitCompoundWordsFilter<A> {
+    dictionary: Set<A>,
+}
+
+impl<A> SplitCompoundWordsFilter<A>
+where
+    A: AsRef<[u8]>,
+{
+    pub fn new(dictionary: Set<A>) -> Self {
+        Self { dictionary }
+    }
+
+    /// Finds the longest dictionary word matching each prefix of `word`, recursing on
+    /// the remainder. Returns `None` if no decomposition covers the whole word.
+    fn split<'w>(&self, word: &'w str) -> Option<Vec<&'w str>> {
+        if word.is_empty() {
+            return Some(Vec::new());
+        }
+
+        let boundaries = word
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain(std::iter::once(word.len()))
+            .skip(1);
+
+        for end in boundaries.collect::<Vec<_>>().into_iter().rev() {
+            let candidate = &word[..end];
+            if self.dictionary.contains(candidate) {
+                if let Some(mut rest) = self.split(&word[end..]) {
+                    let mut parts = vec![candidate];
+                    parts.append(&mut rest);
+                    return Some(parts);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl<A> TokenFilter for SplitCompoundWordsFilter<A>
+where
+    A: AsRef<[u8]> + Sync + Send,
+{
+    fn filter<'a>(&self, mut token: Token<'a>) -> Option<Token<'a>> {
+        if let Some(parts) = self.split(token.word.as_ref()) {
+            if parts.len() > 1 {
+                token.word = Cow::Owned(parts.join(" "));
+            }
+        }
+        Some(token)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::TokenKind;
+
+    fn filter() -> SplitCompoundWordsFilter<Vec<u8>> {
+        // Deliberately doesn't contain "bookcase" itself, so decomposing it exercises
+        // the recursive split rather than a single whole-word dictionary hit.
+        SplitCompoundWordsFilter::new(Set::from_iter(["book", "case"]).unwrap())
+    }
+
+    fn token(word: &str) -> Token {
+        Token {
+            kind: TokenKind::Word,
+            word: Cow::Borrowed(word),
+            byte_start: 0,
+            byte_end: word.len(),
+        }
+    }
+
+    #[test]
+    fn test_splits_compound_word_into_dictionary_sub_words() {
+        let filtered = filter().filter(token("bookcase")).unwrap();
+        assert_eq!("book case", filtered.word);
+    }
+
+    #[test]
+    fn test_passes_through_word_the_dictionary_cant_decompose() {
+        let filtered = filter().filter(token("hello")).unwrap();
+        assert_eq!("hello", filtered.word);
+    }
+}
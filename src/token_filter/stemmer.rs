@@ -0,0 +1,49 @@
+use std::borrow::Cow;
+
+use rust_stemmers::{Algorithm, Stemmer};
+
+use crate::token_filter::TokenFilter;
+use crate::Token;
+
+/// Reduces word tokens to their Snowball stem, e.g. "running" -> "run", so that
+/// inflected forms of a word match each other.
+pub struct StemmerFilter {
+    stemmer: Stemmer,
+}
+
+impl StemmerFilter {
+    pub fn new(language: Algorithm) -> Self {
+        Self {
+            stemmer: Stemmer::create(language),
+        }
+    }
+}
+
+impl TokenFilter for StemmerFilter {
+    fn filter<'a>(&self, mut token: Token<'a>) -> Option<Token<'a>> {
+        token.word = Cow::Owned(self.stemmer.stem(&token.word).into_owned());
+        Some(token)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::TokenKind;
+
+    fn token(word: &str) -> Token {
+        Token {
+            kind: TokenKind::Word,
+            word: Cow::Borrowed(word),
+            byte_start: 0,
+            byte_end: word.len(),
+        }
+    }
+
+    #[test]
+    fn test_stems_inflected_word() {
+        let filter = StemmerFilter::new(Algorithm::English);
+        let filtered = filter.filter(token("running")).unwrap();
+        assert_eq!("run", filtered.word);
+    }
+}
@@ -0,0 +1,49 @@
+use std::borrow::Cow;
+
+use lindera::tokenizer::Tokenizer as LinderaTokenizer;
+use once_cell::sync::OnceCell;
+
+use crate::processors::ProcessedText;
+use crate::tokenizer::{segment_kind, Tokenizer};
+use crate::Token;
+
+/// Segments Japanese text using lindera's morphological dictionary segmenter, since
+/// Japanese has no spaces between words and `UnicodeSegmenter` has no boundaries to
+/// split on.
+///
+/// The dictionary is loaded lazily on first use rather than in `Default::default`, so
+/// building an `AnalyzerConfig` never pays the cost of, or panics on, loading it unless
+/// Japanese text is actually analyzed.
+#[derive(Default)]
+pub struct Japanese {
+    inner: OnceCell<LinderaTokenizer>,
+}
+
+impl Japanese {
+    fn inner(&self) -> &LinderaTokenizer {
+        self.inner
+            .get_or_init(|| LinderaTokenizer::new().expect("failed to load the lindera dictionary"))
+    }
+}
+
+impl Tokenizer for Japanese {
+    fn tokenize<'a>(&self, text: &'a ProcessedText<'a>) -> Box<dyn Iterator<Item = Token<'a>> + 'a> {
+        let processed = text.processed.as_ref();
+        let tokens: Vec<Token<'a>> = self
+            .inner()
+            .tokenize(processed)
+            .expect("lindera tokenization failed")
+            .into_iter()
+            .map(|token| {
+                let word = &processed[token.byte_start..token.byte_end];
+                Token {
+                    kind: segment_kind(word),
+                    word: Cow::Borrowed(word),
+                    byte_start: token.byte_start,
+                    byte_end: token.byte_end,
+                }
+            })
+            .collect();
+        Box::new(tokens.into_iter())
+    }
+}
@@ -0,0 +1,37 @@
+use std::borrow::Cow;
+
+use crate::processors::ProcessedText;
+use crate::tokenizer::{segment_kind, Tokenizer};
+use crate::Token;
+
+/// Segments Mandarin text into words using the Jieba dictionary-based segmenter.
+pub struct Jieba(jieba_rs::Jieba);
+
+impl Default for Jieba {
+    fn default() -> Self {
+        Self(jieba_rs::Jieba::new())
+    }
+}
+
+impl Tokenizer for Jieba {
+    fn tokenize<'a>(&self, text: &'a ProcessedText<'a>) -> Box<dyn Iterator<Item = Token<'a>> + 'a> {
+        let processed = text.processed.as_ref();
+        let mut byte_start = 0;
+        let tokens: Vec<Token<'a>> = self
+            .0
+            .cut(processed, false)
+            .into_iter()
+            .map(move |word| {
+                let start = byte_start;
+                byte_start += word.len();
+                Token {
+                    kind: segment_kind(word),
+                    word: Cow::Borrowed(word),
+                    byte_start: start,
+                    byte_end: byte_start,
+                }
+            })
+            .collect();
+        Box::new(tokens.into_iter())
+    }
+}
@@ -0,0 +1,48 @@
+use std::borrow::Cow;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::processors::ProcessedText;
+use crate::tokenizer::{segment_kind, Tokenizer};
+use crate::{Token, TokenKind};
+
+/// MeiliSearch's original Latin tokenizer: splits on Unicode word boundaries like
+/// `UnicodeSegmenter`, but merges consecutive separator segments (e.g. `" (\""`) into a
+/// single separator token instead of emitting one token per character.
+pub struct LegacyMeilisearch;
+
+impl Tokenizer for LegacyMeilisearch {
+    fn tokenize<'a>(&self, text: &'a ProcessedText<'a>) -> Box<dyn Iterator<Item = Token<'a>> + 'a> {
+        let processed = text.processed.as_ref();
+        let mut segments = processed.split_word_bound_indices().peekable();
+
+        Box::new(std::iter::from_fn(move || {
+            let (byte_start, word) = segments.next()?;
+            let kind = segment_kind(word);
+            if kind == TokenKind::Word {
+                return Some(Token {
+                    kind,
+                    word: Cow::Borrowed(word),
+                    byte_start,
+                    byte_end: byte_start + word.len(),
+                });
+            }
+
+            let mut byte_end = byte_start + word.len();
+            while let Some(&(next_start, next_word)) = segments.peek() {
+                if next_start != byte_end || segment_kind(next_word) == TokenKind::Word {
+                    break;
+                }
+                byte_end += next_word.len();
+                segments.next();
+            }
+
+            Some(Token {
+                kind: TokenKind::Separator,
+                word: Cow::Borrowed(&processed[byte_start..byte_end]),
+                byte_start,
+                byte_end,
+            })
+        }))
+    }
+}
@@ -0,0 +1,61 @@
+mod japanese;
+mod jieba;
+mod legacy_meilisearch;
+mod ngram;
+mod unicode_segmenter;
+
+pub use japanese::Japanese;
+pub use jieba::Jieba;
+pub use legacy_meilisearch::LegacyMeilisearch;
+pub use ngram::NgramTokenizer;
+pub use unicode_segmenter::UnicodeSegmenter;
+
+use crate::processors::ProcessedText;
+use crate::{Token, TokenKind};
+
+pub trait Tokenizer: Sync + Send {
+    fn tokenize<'a>(&self, text: &'a ProcessedText<'a>) -> Box<dyn Iterator<Item = Token<'a>> + 'a>;
+}
+
+impl<T> Tokenizer for Box<T>
+where
+    T: Tokenizer + ?Sized,
+{
+    fn tokenize<'a>(&self, text: &'a ProcessedText<'a>) -> Box<dyn Iterator<Item = Token<'a>> + 'a> {
+        self.as_ref().tokenize(text)
+    }
+}
+
+/// Lets a single tokenizer instance be shared across several pipelines, e.g. the
+/// Japanese pipeline reuses one `Japanese` tokenizer (and its lazily-loaded dictionary)
+/// for all of its `(Script, Language)` entries instead of constructing one per entry.
+impl<T> Tokenizer for std::sync::Arc<T>
+where
+    T: Tokenizer + ?Sized,
+{
+    fn tokenize<'a>(&self, text: &'a ProcessedText<'a>) -> Box<dyn Iterator<Item = Token<'a>> + 'a> {
+        self.as_ref().tokenize(text)
+    }
+}
+
+pub struct TokenStream<'a> {
+    pub(crate) inner: Box<dyn Iterator<Item = Token<'a>> + 'a>,
+}
+
+impl<'a> Iterator for TokenStream<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+/// A segment counts as a word if it contains at least one alphanumeric character;
+/// anything else (whitespace, punctuation, symbols) is a separator.
+pub(crate) fn segment_kind(segment: &str) -> TokenKind {
+    if segment.chars().any(char::is_alphanumeric) {
+        TokenKind::Word
+    } else {
+        TokenKind::Separator
+    }
+}
@@ -0,0 +1,80 @@
+use std::borrow::Cow;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::processors::ProcessedText;
+use crate::tokenizer::{segment_kind, Tokenizer};
+use crate::{Token, TokenKind};
+
+/// Emits every character n-gram of length `min..=max` (counted in Unicode scalars, not
+/// bytes) from each word run of the input. An alternative segmentation strategy for
+/// scripts where dictionary segmentation is unavailable, or where substring/infix search
+/// is wanted, e.g. bigram-indexing unknown CJK. When `edges_only` is set, only the
+/// prefixes anchored at the start of each run are emitted (edge n-grams, useful for
+/// autocomplete).
+pub struct NgramTokenizer {
+    pub min: usize,
+    pub max: usize,
+    pub edges_only: bool,
+}
+
+impl NgramTokenizer {
+    pub fn new(min: usize, max: usize, edges_only: bool) -> Self {
+        Self {
+            min,
+            max,
+            edges_only,
+        }
+    }
+}
+
+impl Tokenizer for NgramTokenizer {
+    fn tokenize<'a>(&self, text: &'a ProcessedText<'a>) -> Box<dyn Iterator<Item = Token<'a>> + 'a> {
+        let processed = text.processed.as_ref();
+        let min = self.min;
+        let max = self.max;
+        let edges_only = self.edges_only;
+
+        let mut tokens = Vec::new();
+        for (run_start, run) in processed.split_word_bound_indices() {
+            if segment_kind(run) != TokenKind::Word {
+                continue;
+            }
+
+            // Byte offset (relative to `run_start`) of every char boundary in the run,
+            // plus a trailing sentinel at `run.len()` so a n-gram ending at the last
+            // char can still look up its end offset.
+            let char_offsets: Vec<usize> = run
+                .char_indices()
+                .map(|(byte_offset, _)| byte_offset)
+                .chain(std::iter::once(run.len()))
+                .collect();
+            let char_count = char_offsets.len() - 1;
+            if char_count < min {
+                continue;
+            }
+
+            for len in min..=max.min(char_count) {
+                let last_start_char = char_count - len;
+                let start_chars: Box<dyn Iterator<Item = usize>> = if edges_only {
+                    Box::new(std::iter::once(0))
+                } else {
+                    Box::new(0..=last_start_char)
+                };
+
+                for start_char in start_chars {
+                    let byte_start = run_start + char_offsets[start_char];
+                    let byte_end = run_start + char_offsets[start_char + len];
+                    tokens.push(Token {
+                        kind: TokenKind::Word,
+                        word: Cow::Borrowed(&processed[byte_start..byte_end]),
+                        byte_start,
+                        byte_end,
+                    });
+                }
+            }
+        }
+
+        Box::new(tokens.into_iter())
+    }
+}
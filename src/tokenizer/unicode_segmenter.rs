@@ -0,0 +1,28 @@
+use std::borrow::Cow;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::processors::ProcessedText;
+use crate::tokenizer::{segment_kind, Tokenizer};
+use crate::Token;
+
+/// Splits on Unicode word boundaries (UAX #29), emitting one token per boundary
+/// without merging adjacent separators. Used as the library-wide fallback when no
+/// script-specific tokenizer applies.
+pub struct UnicodeSegmenter;
+
+impl Tokenizer for UnicodeSegmenter {
+    fn tokenize<'a>(&self, text: &'a ProcessedText<'a>) -> Box<dyn Iterator<Item = Token<'a>> + 'a> {
+        let processed = text.processed.as_ref();
+        Box::new(
+            processed
+                .split_word_bound_indices()
+                .map(move |(byte_start, word)| Token {
+                    kind: segment_kind(word),
+                    word: Cow::Borrowed(word),
+                    byte_start,
+                    byte_end: byte_start + word.len(),
+                }),
+        )
+    }
+}